@@ -1,7 +1,10 @@
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     io,
-    sync::mpsc::{self, Receiver, Sender},
+    sync::{
+        mpsc::{self, Receiver, Sender},
+        Arc,
+    },
     time::Duration,
 };
 
@@ -9,7 +12,9 @@ use crossterm::{
     event::{self, Event, KeyCode, KeyEvent},
     execute, terminal,
 };
-use hn_core::{HackerNewsClient, HackerNewsComment, HackerNewsItem};
+use hn_core::{
+    CommentNode, FileCache, HackerNewsClient, HackerNewsItem, StoryFeed, StoryNumericFilters,
+};
 use ratatui::{
     backend::CrosstermBackend,
     layout::{Alignment, Constraint, Direction, Layout},
@@ -20,14 +25,25 @@ use ratatui::{
 };
 
 const COMMENT_LIMIT: usize = 10;
+const COMMENT_MAX_DEPTH: usize = 6;
+const STORY_LIMIT: usize = 20;
+const CACHE_TTL_SECS: u64 = 600;
 const POLL_INTERVAL: Duration = Duration::from_millis(150);
 
 #[tokio::main]
 async fn main() -> color_eyre::Result<()> {
     color_eyre::install()?;
 
-    let client = HackerNewsClient::default();
-    let posts = client.fetch_top_stories(20).await?;
+    let args = CliArgs::parse()?;
+    let client = HackerNewsClient::default().with_cache(Arc::new(FileCache::new()), CACHE_TTL_SECS);
+
+    let app = if let Some(id) = args.item {
+        let item = client.fetch_item(id).await?;
+        App::for_item(item, args.limit)
+    } else {
+        let posts = client.fetch_stories(args.feed, args.limit).await?;
+        App::new(args.feed, posts, args.limit)
+    };
 
     let mut stdout = io::stdout();
     execute!(stdout, terminal::EnterAlternateScreen)?;
@@ -36,7 +52,6 @@ async fn main() -> color_eyre::Result<()> {
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
-    let app = App::new(posts);
     let res = run_app(&mut terminal, app, client);
 
     terminal::disable_raw_mode()?;
@@ -52,21 +67,31 @@ fn run_app(
     client: HackerNewsClient,
 ) -> color_eyre::Result<()> {
     let (tx, rx) = mpsc::channel::<CommentFetchMessage>();
+    let (posts_tx, posts_rx) = mpsc::channel::<StoryFetchMessage>();
+    let (search_tx, search_rx) = mpsc::channel::<SearchFetchMessage>();
     app.ensure_comments_for_selection(&client, &tx);
 
     loop {
         drain_comment_messages(&mut app, &rx);
+        drain_story_messages(&mut app, &posts_rx, &client, &tx);
+        drain_search_messages(&mut app, &search_rx, &client, &tx);
 
         terminal.draw(|frame| draw_ui(frame, &mut app))?;
 
         if event::poll(POLL_INTERVAL)? {
             if let Event::Key(key) = event::read()? {
-                let (should_quit, selection_changed) = app.handle_key_event(key);
-                if should_quit {
-                    break;
-                }
-                if selection_changed {
-                    app.ensure_comments_for_selection(&client, &tx);
+                match app.handle_key_event(key) {
+                    KeyAction::Quit => break,
+                    KeyAction::SelectionChanged => {
+                        app.ensure_comments_for_selection(&client, &tx);
+                    }
+                    KeyAction::FeedChanged => {
+                        app.switch_feed(&client, &posts_tx);
+                    }
+                    KeyAction::Search(query) => {
+                        app.start_search(&client, &search_tx, query);
+                    }
+                    KeyAction::None => {}
                 }
             }
         }
@@ -75,12 +100,60 @@ fn run_app(
     Ok(())
 }
 
+/// Fetch the top-level comments of a story as threaded trees.
+async fn fetch_comment_forest(
+    client: &HackerNewsClient,
+    post: &HackerNewsItem,
+) -> Result<Vec<CommentNode>, String> {
+    let roots = post.kids.iter().copied().take(COMMENT_LIMIT);
+    let threads = futures::future::join_all(
+        roots.map(|id| client.fetch_comment_thread(id, COMMENT_MAX_DEPTH, COMMENT_LIMIT)),
+    )
+    .await;
+
+    let mut forest = Vec::new();
+    for thread in threads {
+        match thread {
+            Ok(node) => forest.push(node),
+            Err(err) => tracing::warn!(?err, "skipping comment thread failure"),
+        }
+    }
+
+    Ok(forest)
+}
+
 fn drain_comment_messages(app: &mut App, rx: &Receiver<CommentFetchMessage>) {
     while let Ok(message) = rx.try_recv() {
         app.process_comment_message(message);
     }
 }
 
+fn drain_story_messages(
+    app: &mut App,
+    rx: &Receiver<StoryFetchMessage>,
+    client: &HackerNewsClient,
+    tx: &Sender<CommentFetchMessage>,
+) {
+    while let Ok(message) = rx.try_recv() {
+        if app.process_story_message(message) {
+            app.ensure_comments_for_selection(client, tx);
+        }
+    }
+}
+
+fn drain_search_messages(
+    app: &mut App,
+    rx: &Receiver<SearchFetchMessage>,
+    client: &HackerNewsClient,
+    tx: &Sender<CommentFetchMessage>,
+) {
+    while let Ok(message) = rx.try_recv() {
+        if app.process_search_message(message) {
+            app.ensure_comments_for_selection(client, tx);
+        }
+    }
+}
+
 fn draw_ui(frame: &mut Frame, app: &mut App) {
     let root_chunks = Layout::default()
         .direction(Direction::Vertical)
@@ -88,7 +161,19 @@ fn draw_ui(frame: &mut Frame, app: &mut App) {
         .constraints([Constraint::Length(3), Constraint::Min(1)].as_ref())
         .split(frame.size());
 
-    let header = Paragraph::new("Hacker News — Shared Rust WebAssembly")
+    let header_text = if let Some(buf) = &app.search_input {
+        format!("Search: {buf}▏  (Enter: run, Esc: cancel)")
+    } else if let Some(query) = &app.search_query {
+        format!("Search results — {query}  (Tab: back to feed, /: new search)")
+    } else if app.standalone.is_some() {
+        "Hacker News — Story  (q: quit)".to_string()
+    } else {
+        format!(
+            "Hacker News — {} Stories  (Tab: feed, /: search)",
+            app.feed.label()
+        )
+    };
+    let header = Paragraph::new(header_text)
         .style(
             Style::default()
                 .fg(Color::Yellow)
@@ -107,6 +192,24 @@ fn draw_ui(frame: &mut Frame, app: &mut App) {
 }
 
 fn render_posts(frame: &mut Frame, area: ratatui::layout::Rect, app: &mut App) {
+    if let Some(post) = &app.standalone {
+        let lines = vec![
+            Line::from(Span::styled(
+                post.title.clone(),
+                Style::default().add_modifier(Modifier::BOLD),
+            )),
+            Line::from(Span::styled(
+                format!("{} points • {}", post.score, post.by),
+                Style::default().fg(Color::Gray),
+            )),
+        ];
+        let summary = Paragraph::new(lines)
+            .block(Block::default().title("Story").borders(Borders::ALL))
+            .wrap(Wrap { trim: true });
+        frame.render_widget(summary, area);
+        return;
+    }
+
     let items: Vec<ListItem> = app
         .posts
         .iter()
@@ -121,8 +224,12 @@ fn render_posts(frame: &mut Frame, area: ratatui::layout::Rect, app: &mut App) {
         })
         .collect();
 
+    let list_title = match &app.search_query {
+        Some(_) => "Results".to_string(),
+        None => format!("{} Stories", app.feed.label()),
+    };
     let list = List::new(items)
-        .block(Block::default().title("Top Stories").borders(Borders::ALL))
+        .block(Block::default().title(list_title).borders(Borders::ALL))
         .highlight_style(
             Style::default()
                 .bg(Color::Blue)
@@ -134,9 +241,9 @@ fn render_posts(frame: &mut Frame, area: ratatui::layout::Rect, app: &mut App) {
     frame.render_stateful_widget(list, area, app.list_state());
 }
 
-fn render_comments(frame: &mut Frame, area: ratatui::layout::Rect, app: &App) {
+fn render_comments(frame: &mut Frame, area: ratatui::layout::Rect, app: &mut App) {
     let title = app
-        .selected_post()
+        .active_post()
         .map(|post| format!("Comments — {}", post.title))
         .unwrap_or_else(|| "Comments".to_string());
 
@@ -162,28 +269,27 @@ fn render_comments(frame: &mut Frame, area: ratatui::layout::Rect, app: &App) {
                         .alignment(Alignment::Center);
                     frame.render_widget(paragraph, area);
                 } else {
-                    let items: Vec<ListItem> = comments
+                    let mut rows = Vec::new();
+                    flatten_comments(comments, &app.collapsed, 0, &mut rows);
+
+                    let items: Vec<ListItem> = rows
                         .iter()
-                        .map(|comment| {
-                            let header = format!("{} (#{})", comment.by, comment.id);
-                            let text = sanitize_comment_text(&comment.text);
-                            ListItem::new(vec![
-                                Line::from(Span::styled(
-                                    header,
-                                    Style::default()
-                                        .fg(Color::Cyan)
-                                        .add_modifier(Modifier::BOLD),
-                                )),
-                                Line::from(text),
-                                Line::from(""),
-                            ])
-                        })
+                        .map(|row| comment_list_item(row, &app.collapsed))
                         .collect();
 
+                    let highlight = if app.focus == Focus::Comments {
+                        Style::default()
+                            .bg(Color::Blue)
+                            .add_modifier(Modifier::BOLD)
+                    } else {
+                        Style::default()
+                    };
+
                     let comment_list = List::new(items)
                         .block(comments_block(&title))
+                        .highlight_style(highlight)
                         .highlight_symbol("");
-                    frame.render_widget(comment_list, area);
+                    frame.render_stateful_widget(comment_list, area, &mut app.comment_state);
                 }
             } else {
                 let paragraph = Paragraph::new("Select a post to view comments.")
@@ -201,30 +307,218 @@ fn render_comments(frame: &mut Frame, area: ratatui::layout::Rect, app: &App) {
     }
 }
 
+/// A comment node paired with its depth for flattened rendering.
+struct FlatComment<'a> {
+    depth: usize,
+    node: &'a CommentNode,
+}
+
+/// Depth-first flatten of a comment forest, skipping the replies of any node
+/// the user has collapsed.
+fn flatten_comments<'a>(
+    nodes: &'a [CommentNode],
+    collapsed: &HashSet<u64>,
+    depth: usize,
+    out: &mut Vec<FlatComment<'a>>,
+) {
+    for node in nodes {
+        out.push(FlatComment { depth, node });
+        if !collapsed.contains(&node.comment.id) {
+            flatten_comments(&node.replies, collapsed, depth + 1, out);
+        }
+    }
+}
+
+fn comment_list_item(row: &FlatComment<'_>, collapsed: &HashSet<u64>) -> ListItem<'static> {
+    let comment = &row.node.comment;
+    let indent = "  ".repeat(row.depth);
+
+    let marker = if row.node.replies.is_empty() {
+        " ".to_string()
+    } else if collapsed.contains(&comment.id) {
+        format!("[+{}] ", row.node.replies.len())
+    } else {
+        "[-] ".to_string()
+    };
+
+    let header = format!("{indent}{marker}{} (#{})", comment.by, comment.id);
+    let mut lines = vec![Line::from(Span::styled(
+        header,
+        Style::default()
+            .fg(Color::Cyan)
+            .add_modifier(Modifier::BOLD),
+    ))];
+
+    for line in render_comment(&comment.text) {
+        lines.push(indent_line(&indent, line));
+    }
+    lines.push(Line::from(""));
+
+    ListItem::new(lines)
+}
+
+/// Find a comment node by id anywhere in a forest.
+fn find_comment<'a>(nodes: &'a [CommentNode], id: u64) -> Option<&'a CommentNode> {
+    for node in nodes {
+        if node.comment.id == id {
+            return Some(node);
+        }
+        if let Some(found) = find_comment(&node.replies, id) {
+            return Some(found);
+        }
+    }
+    None
+}
+
+/// Best-effort launch of the system browser for a URL.
+fn open_url(url: &str) {
+    #[cfg(target_os = "macos")]
+    let program = "open";
+    #[cfg(target_os = "windows")]
+    let program = "explorer";
+    #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+    let program = "xdg-open";
+
+    let _ = std::process::Command::new(program).arg(url).spawn();
+}
+
+/// Prepend a raw indentation span to an already-styled line.
+fn indent_line(indent: &str, line: Line<'static>) -> Line<'static> {
+    if indent.is_empty() {
+        return line;
+    }
+    let mut spans = Vec::with_capacity(line.spans.len() + 1);
+    spans.push(Span::raw(indent.to_string()));
+    spans.extend(line.spans);
+    Line::from(spans)
+}
+
+/// Command-line options for deep-linking into a feed or straight into a story.
+struct CliArgs {
+    /// Open directly into this story's comment view.
+    item: Option<u64>,
+    /// Which feed to load the post list from.
+    feed: StoryFeed,
+    /// How many stories to fetch for the post list.
+    limit: usize,
+}
+
+impl Default for CliArgs {
+    fn default() -> Self {
+        Self {
+            item: None,
+            feed: StoryFeed::default(),
+            limit: STORY_LIMIT,
+        }
+    }
+}
+
+impl CliArgs {
+    /// Parse `--item <id>`, `--feed <top|new|best|ask|show|job>`, and
+    /// `--limit <n>` from the process arguments.
+    fn parse() -> color_eyre::Result<Self> {
+        use color_eyre::eyre::eyre;
+
+        let mut args = CliArgs::default();
+        let mut raw = std::env::args().skip(1);
+        while let Some(flag) = raw.next() {
+            let mut value = || raw.next().ok_or_else(|| eyre!("{flag} requires a value"));
+            match flag.as_str() {
+                "--item" => {
+                    let v = value()?;
+                    args.item = Some(v.parse().map_err(|_| eyre!("invalid --item id: {v}"))?);
+                }
+                "--feed" => {
+                    let v = value()?;
+                    args.feed = StoryFeed::from_name(&v)
+                        .ok_or_else(|| eyre!("unknown --feed: {v}"))?;
+                }
+                "--limit" => {
+                    let v = value()?;
+                    args.limit = v.parse().map_err(|_| eyre!("invalid --limit: {v}"))?;
+                }
+                other => return Err(eyre!("unrecognised argument: {other}")),
+            }
+        }
+        Ok(args)
+    }
+}
+
 struct App {
+    feed: StoryFeed,
+    /// How many stories to request when (re)loading a feed.
+    limit: usize,
     posts: Vec<HackerNewsItem>,
     list_state: ListState,
-    comments_cache: HashMap<u64, Vec<HackerNewsComment>>,
+    comments_cache: HashMap<u64, Vec<CommentNode>>,
+    comment_state: ListState,
+    collapsed: HashSet<u64>,
+    focus: Focus,
+    /// The query being typed, if the search bar is open.
+    search_input: Option<String>,
+    /// The query whose results currently populate the post list, if any.
+    search_query: Option<String>,
     inflight_story: Option<u64>,
+    inflight_feed: Option<StoryFeed>,
     comment_status: CommentStatus,
+    /// A deep-linked story shown on its own, independent of the post list.
+    /// When set, the comment pane follows this item rather than `list_state`.
+    standalone: Option<HackerNewsItem>,
+    /// When deep-linked via `--item`, jump into the comment view as soon as the
+    /// story's comments finish loading.
+    deep_link: bool,
+}
+
+/// Which pane currently receives navigation keys.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Focus {
+    Posts,
+    Comments,
 }
 
 impl App {
-    fn new(posts: Vec<HackerNewsItem>) -> Self {
+    fn new(feed: StoryFeed, posts: Vec<HackerNewsItem>, limit: usize) -> Self {
         let mut list_state = ListState::default();
         if !posts.is_empty() {
             list_state.select(Some(0));
         }
 
         Self {
+            feed,
+            limit,
             posts,
             list_state,
             comments_cache: HashMap::new(),
+            comment_state: ListState::default(),
+            collapsed: HashSet::new(),
+            focus: Focus::Posts,
+            search_input: None,
+            search_query: None,
             inflight_story: None,
+            inflight_feed: None,
             comment_status: CommentStatus::Idle,
+            standalone: None,
+            deep_link: false,
         }
     }
 
+    /// Build an app deep-linked to a single story, ready to drop straight into
+    /// its comment view once the comments arrive. The story stands alone: there
+    /// is no surrounding post list to navigate.
+    fn for_item(item: HackerNewsItem, limit: usize) -> Self {
+        let mut app = App::new(StoryFeed::default(), Vec::new(), limit);
+        app.standalone = Some(item);
+        app.deep_link = true;
+        app
+    }
+
+    /// The story the comment pane is currently following: the deep-linked
+    /// standalone item if present, otherwise the selected post. This is what
+    /// decouples comment fetching from the posts `Vec`.
+    fn active_post(&self) -> Option<&HackerNewsItem> {
+        self.standalone.as_ref().or_else(|| self.selected_post())
+    }
+
     fn list_state(&mut self) -> &mut ListState {
         &mut self.list_state
     }
@@ -235,8 +529,8 @@ impl App {
             .and_then(|idx| self.posts.get(idx))
     }
 
-    fn comments_for_selected(&self) -> Option<&Vec<HackerNewsComment>> {
-        let post = self.selected_post()?;
+    fn comments_for_selected(&self) -> Option<&Vec<CommentNode>> {
+        let post = self.active_post()?;
         self.comments_cache.get(&post.id)
     }
 
@@ -245,7 +539,11 @@ impl App {
         client: &HackerNewsClient,
         tx: &Sender<CommentFetchMessage>,
     ) {
-        if let Some(post) = self.selected_post().cloned() {
+        // A new selection starts with a fresh, posts-focused comment pane.
+        self.comment_state.select(None);
+        self.focus = Focus::Posts;
+
+        if let Some(post) = self.active_post().cloned() {
             if self.comments_cache.contains_key(&post.id) {
                 self.comment_status = CommentStatus::Ready;
                 self.inflight_story = None;
@@ -258,10 +556,7 @@ impl App {
                 let tx = tx.clone();
                 let client = client.clone();
                 tokio::spawn(async move {
-                    let result = client
-                        .fetch_comments_for(&post, COMMENT_LIMIT)
-                        .await
-                        .map_err(|err| err.to_string());
+                    let result = fetch_comment_forest(&client, &post).await;
 
                     let _ = tx.send(CommentFetchMessage {
                         story_id: post.id,
@@ -275,12 +570,256 @@ impl App {
         }
     }
 
-    fn handle_key_event(&mut self, key: KeyEvent) -> (bool, bool) {
+    fn handle_key_event(&mut self, key: KeyEvent) -> KeyAction {
+        if self.search_input.is_some() {
+            return self.handle_search_key(key);
+        }
+
         match key.code {
-            KeyCode::Char('q') | KeyCode::Esc => (true, false),
-            KeyCode::Down | KeyCode::Char('j') => (false, self.move_selection(1)),
-            KeyCode::Up | KeyCode::Char('k') => (false, self.move_selection(-1)),
-            _ => (false, false),
+            KeyCode::Char('q') | KeyCode::Esc => KeyAction::Quit,
+            KeyCode::Char('/') => {
+                self.search_input = Some(String::new());
+                KeyAction::None
+            }
+            KeyCode::Tab => KeyAction::FeedChanged,
+            KeyCode::Enter | KeyCode::Right | KeyCode::Char('l') => {
+                self.focus_comments();
+                KeyAction::None
+            }
+            KeyCode::Backspace | KeyCode::Left | KeyCode::Char('h') => {
+                self.focus = Focus::Posts;
+                KeyAction::None
+            }
+            KeyCode::Char(' ') if self.focus == Focus::Comments => {
+                self.toggle_collapse();
+                KeyAction::None
+            }
+            KeyCode::Char('o') if self.focus == Focus::Comments => {
+                self.open_selected_link();
+                KeyAction::None
+            }
+            KeyCode::Down | KeyCode::Char('j') => match self.focus {
+                Focus::Posts => selection_action(self.move_selection(1)),
+                Focus::Comments => {
+                    self.move_comment_selection(1);
+                    KeyAction::None
+                }
+            },
+            KeyCode::Up | KeyCode::Char('k') => match self.focus {
+                Focus::Posts => selection_action(self.move_selection(-1)),
+                Focus::Comments => {
+                    self.move_comment_selection(-1);
+                    KeyAction::None
+                }
+            },
+            _ => KeyAction::None,
+        }
+    }
+
+    /// Handle a key press while the search bar is open.
+    fn handle_search_key(&mut self, key: KeyEvent) -> KeyAction {
+        let Some(buf) = self.search_input.as_mut() else {
+            return KeyAction::None;
+        };
+        match key.code {
+            KeyCode::Esc => {
+                self.search_input = None;
+                KeyAction::None
+            }
+            KeyCode::Enter => {
+                let query = self.search_input.take().unwrap_or_default();
+                if query.trim().is_empty() {
+                    KeyAction::None
+                } else {
+                    KeyAction::Search(query)
+                }
+            }
+            KeyCode::Backspace => {
+                buf.pop();
+                KeyAction::None
+            }
+            KeyCode::Char(c) => {
+                buf.push(c);
+                KeyAction::None
+            }
+            _ => KeyAction::None,
+        }
+    }
+
+    /// Run a search in the background and swap the post list for its results.
+    fn start_search(
+        &mut self,
+        client: &HackerNewsClient,
+        tx: &Sender<SearchFetchMessage>,
+        query: String,
+    ) {
+        self.search_query = Some(query.clone());
+        self.standalone = None;
+
+        let tx = tx.clone();
+        let client = client.clone();
+        tokio::spawn(async move {
+            let filters = StoryNumericFilters {
+                tags: Some("story".to_string()),
+                ..StoryNumericFilters::default()
+            };
+            let result = client
+                .search(&query, &filters)
+                .await
+                .map_err(|err| err.to_string());
+
+            let _ = tx.send(SearchFetchMessage { query, result });
+        });
+    }
+
+    /// Apply completed search results. Returns `true` when the post list
+    /// changed so comments can be refreshed for the new selection.
+    fn process_search_message(&mut self, message: SearchFetchMessage) -> bool {
+        if self.search_query.as_deref() != Some(message.query.as_str()) {
+            return false;
+        }
+
+        match message.result {
+            Ok(posts) => {
+                self.posts = posts;
+                self.comments_cache.clear();
+                self.inflight_story = None;
+                self.list_state = ListState::default();
+                if !self.posts.is_empty() {
+                    self.list_state.select(Some(0));
+                }
+                true
+            }
+            Err(err) => {
+                self.comment_status = CommentStatus::Error(err);
+                false
+            }
+        }
+    }
+
+    /// The ids of the comments currently visible (collapsed subtrees excluded),
+    /// in render order.
+    fn visible_comment_ids(&self) -> Vec<u64> {
+        let mut rows = Vec::new();
+        if let Some(comments) = self.comments_for_selected() {
+            flatten_comments(comments, &self.collapsed, 0, &mut rows);
+        }
+        rows.into_iter().map(|row| row.node.comment.id).collect()
+    }
+
+    fn focus_comments(&mut self) {
+        if self.visible_comment_ids().is_empty() {
+            return;
+        }
+        self.focus = Focus::Comments;
+        if self.comment_state.selected().is_none() {
+            self.comment_state.select(Some(0));
+        }
+    }
+
+    fn move_comment_selection(&mut self, delta: isize) {
+        let len = self.visible_comment_ids().len() as isize;
+        if len == 0 {
+            return;
+        }
+        let current = self.comment_state.selected().unwrap_or(0) as isize;
+        let mut next = current + delta;
+        if next < 0 {
+            next = len - 1;
+        } else if next >= len {
+            next = 0;
+        }
+        self.comment_state.select(Some(next as usize));
+    }
+
+    /// Open the first link in the selected comment with the system browser.
+    fn open_selected_link(&self) {
+        let visible = self.visible_comment_ids();
+        let Some(idx) = self.comment_state.selected() else {
+            return;
+        };
+        let Some(id) = visible.get(idx).copied() else {
+            return;
+        };
+        let Some(comments) = self.comments_for_selected() else {
+            return;
+        };
+        if let Some(node) = find_comment(comments, id) {
+            if let Some(url) = first_comment_link(&node.comment.text) {
+                open_url(&url);
+            }
+        }
+    }
+
+    fn toggle_collapse(&mut self) {
+        let visible = self.visible_comment_ids();
+        let Some(idx) = self.comment_state.selected() else {
+            return;
+        };
+        let Some(id) = visible.get(idx).copied() else {
+            return;
+        };
+        if !self.collapsed.remove(&id) {
+            self.collapsed.insert(id);
+        }
+        // Collapsing shrinks the visible list; keep the selection in range.
+        let len = self.visible_comment_ids().len();
+        if len == 0 {
+            self.comment_state.select(None);
+        } else if idx >= len {
+            self.comment_state.select(Some(len - 1));
+        }
+    }
+
+    /// Advance to the next feed (or, when leaving search results, reload the
+    /// feed they replaced) and kick off a background re-fetch of its posts.
+    fn switch_feed(&mut self, client: &HackerNewsClient, tx: &Sender<StoryFetchMessage>) {
+        let feed = if self.search_query.is_some() {
+            self.feed
+        } else {
+            self.feed.next()
+        };
+        self.feed = feed;
+        self.search_query = None;
+        self.standalone = None;
+        self.inflight_feed = Some(feed);
+
+        let tx = tx.clone();
+        let client = client.clone();
+        let limit = self.limit;
+        tokio::spawn(async move {
+            let result = client
+                .fetch_stories(feed, limit)
+                .await
+                .map_err(|err| err.to_string());
+
+            let _ = tx.send(StoryFetchMessage { feed, result });
+        });
+    }
+
+    /// Apply a completed story fetch. Returns `true` when the post list changed
+    /// so the caller can refresh comments for the new selection.
+    fn process_story_message(&mut self, message: StoryFetchMessage) -> bool {
+        if self.inflight_feed != Some(message.feed) || self.feed != message.feed {
+            return false;
+        }
+        self.inflight_feed = None;
+
+        match message.result {
+            Ok(posts) => {
+                self.posts = posts;
+                self.comments_cache.clear();
+                self.inflight_story = None;
+                self.list_state = ListState::default();
+                if !self.posts.is_empty() {
+                    self.list_state.select(Some(0));
+                }
+                true
+            }
+            Err(err) => {
+                self.comment_status = CommentStatus::Error(err);
+                false
+            }
         }
     }
 
@@ -308,7 +847,7 @@ impl App {
     }
 
     fn selected_post_id(&self) -> Option<u64> {
-        self.selected_post().map(|post| post.id)
+        self.active_post().map(|post| post.id)
     }
 
     fn process_comment_message(&mut self, message: CommentFetchMessage) {
@@ -321,6 +860,10 @@ impl App {
                 self.comments_cache.insert(message.story_id, comments);
                 if self.selected_post_id() == Some(message.story_id) {
                     self.comment_status = CommentStatus::Ready;
+                    if self.deep_link {
+                        self.focus_comments();
+                        self.deep_link = false;
+                    }
                 }
             }
             Err(err) => {
@@ -332,6 +875,23 @@ impl App {
     }
 }
 
+/// The effect a key press has on the run loop.
+enum KeyAction {
+    None,
+    Quit,
+    SelectionChanged,
+    FeedChanged,
+    Search(String),
+}
+
+fn selection_action(changed: bool) -> KeyAction {
+    if changed {
+        KeyAction::SelectionChanged
+    } else {
+        KeyAction::None
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 enum CommentStatus {
     Idle,
@@ -348,61 +908,240 @@ fn comments_block(title: &str) -> Block<'static> {
 
 struct CommentFetchMessage {
     story_id: u64,
-    result: Result<Vec<HackerNewsComment>, String>,
-}
-
-fn sanitize_comment_text(input: &str) -> String {
-    let replacements = [
-        ("<p>", "\n\n"),
-        ("</p>", ""),
-        ("<i>", ""),
-        ("</i>", ""),
-        ("<em>", ""),
-        ("</em>", ""),
-        ("<strong>", ""),
-        ("</strong>", ""),
-        ("<code>", "`"),
-        ("</code>", "`"),
-        ("<pre>", "\n"),
-        ("</pre>", "\n"),
-        ("<br>", "\n"),
-        ("<br/>", "\n"),
-        ("<br />", "\n"),
-        ("&gt;", ">"),
-        ("&lt;", "<"),
-        ("&amp;", "&"),
-        ("&quot;", "\""),
-        ("&#x27;", "'"),
-        ("&#x2F;", "/"),
-        ("&nbsp;", " "),
-    ];
-
-    let mut output = input.to_string();
-    for (from, to) in replacements {
-        output = output.replace(from, to);
-    }
-
-    strip_tags(&output).trim().to_string()
-}
-
-fn strip_tags(input: &str) -> String {
-    let mut result = String::with_capacity(input.len());
-    let mut in_tag = false;
-    for ch in input.chars() {
-        match ch {
-            '<' => {
-                in_tag = true;
+    result: Result<Vec<CommentNode>, String>,
+}
+
+struct StoryFetchMessage {
+    feed: StoryFeed,
+    result: Result<Vec<HackerNewsItem>, String>,
+}
+
+struct SearchFetchMessage {
+    query: String,
+    result: Result<Vec<HackerNewsItem>, String>,
+}
+
+/// Parse the limited HTML subset HN emits (`<p>`, `<i>`, `<a href>`,
+/// `<pre><code>`, and entity escapes) into styled ratatui lines: italics carry
+/// `Modifier::ITALIC`, code keeps its whitespace in a distinct colour,
+/// quoted lines (starting `>`) are muted, and links render underlined.
+fn render_comment(input: &str) -> Vec<Line<'static>> {
+    let mut renderer = CommentRenderer::default();
+    renderer.run(input);
+    renderer.finish()
+}
+
+/// The first `href` linked from a comment, if any — used to open a link from
+/// the keyboard.
+fn first_comment_link(input: &str) -> Option<String> {
+    let mut renderer = CommentRenderer::default();
+    renderer.run(input);
+    renderer.links.into_iter().next()
+}
+
+#[derive(Default)]
+struct CommentRenderer {
+    lines: Vec<Line<'static>>,
+    current: Vec<Span<'static>>,
+    italic: bool,
+    code: bool,
+    link: bool,
+    links: Vec<String>,
+}
+
+impl CommentRenderer {
+    fn run(&mut self, input: &str) {
+        let bytes = input.as_bytes();
+        let mut idx = 0;
+        let mut text = String::new();
+
+        while idx < bytes.len() {
+            if bytes[idx] == b'<' {
+                if let Some(end) = input[idx..].find('>') {
+                    self.push_text(&std::mem::take(&mut text));
+                    self.handle_tag(&input[idx + 1..idx + end]);
+                    idx += end + 1;
+                    continue;
+                }
+            }
+            let ch_len = utf8_len(bytes[idx]);
+            text.push_str(&input[idx..idx + ch_len]);
+            idx += ch_len;
+        }
+
+        self.push_text(&text);
+    }
+
+    fn handle_tag(&mut self, raw: &str) {
+        let raw = raw.trim();
+        let closing = raw.starts_with('/');
+        let body = raw.trim_start_matches('/');
+        let name = body
+            .split(|c: char| c.is_whitespace())
+            .next()
+            .unwrap_or("")
+            .to_ascii_lowercase();
+
+        match name.as_str() {
+            "p" => {
+                self.flush_line();
+                self.lines.push(Line::from(""));
             }
-            '>' => {
-                in_tag = false;
+            "br" => self.flush_line(),
+            "i" | "em" => self.italic = !closing,
+            "code" | "pre" => self.code = !closing,
+            "a" => {
+                if closing {
+                    self.link = false;
+                } else {
+                    self.link = true;
+                    if let Some(href) = parse_href(body) {
+                        self.links.push(href);
+                    }
+                }
             }
-            _ => {
-                if !in_tag {
-                    result.push(ch);
+            _ => {}
+        }
+    }
+
+    fn push_text(&mut self, raw: &str) {
+        if raw.is_empty() {
+            return;
+        }
+        let decoded = decode_entities(raw);
+        if self.code {
+            let mut first = true;
+            for segment in decoded.split('\n') {
+                if !first {
+                    self.flush_line();
                 }
+                first = false;
+                self.push_span(segment.to_string());
+            }
+        } else {
+            self.push_span(decoded);
+        }
+    }
+
+    fn push_span(&mut self, text: String) {
+        if text.is_empty() {
+            return;
+        }
+        self.current.push(Span::styled(text, self.style()));
+    }
+
+    fn style(&self) -> Style {
+        let mut style = Style::default();
+        if self.code {
+            style = style.fg(Color::Green);
+        }
+        if self.link {
+            style = style.fg(Color::Cyan).add_modifier(Modifier::UNDERLINED);
+        }
+        if self.italic {
+            style = style.add_modifier(Modifier::ITALIC);
+        }
+        style
+    }
+
+    fn flush_line(&mut self) {
+        let spans = std::mem::take(&mut self.current);
+        let text: String = spans.iter().map(|s| s.content.as_ref()).collect();
+        if text.trim_start().starts_with('>') {
+            // Muted blockquote: restyle the whole line.
+            let muted = spans
+                .into_iter()
+                .map(|s| {
+                    Span::styled(
+                        s.content.into_owned(),
+                        Style::default().fg(Color::DarkGray),
+                    )
+                })
+                .collect::<Vec<_>>();
+            self.lines.push(Line::from(muted));
+        } else {
+            self.lines.push(Line::from(spans));
+        }
+    }
+
+    fn finish(mut self) -> Vec<Line<'static>> {
+        self.flush_line();
+        while self
+            .lines
+            .last()
+            .is_some_and(|line| line.spans.is_empty())
+        {
+            self.lines.pop();
+        }
+        self.lines
+    }
+}
+
+/// Length in bytes of the UTF-8 sequence whose leading byte is `b`.
+fn utf8_len(b: u8) -> usize {
+    match b {
+        0x00..=0x7F => 1,
+        0xC0..=0xDF => 2,
+        0xE0..=0xEF => 3,
+        _ => 4,
+    }
+}
+
+/// Extract the `href` value from an anchor tag body (`a href="..."`).
+fn parse_href(body: &str) -> Option<String> {
+    let rest = &body[body.find("href")?..];
+    let rest = &rest[rest.find('=')? + 1..];
+    let rest = rest.trim_start();
+    let quote = rest.chars().next()?;
+    if quote == '"' || quote == '\'' {
+        let rest = &rest[1..];
+        let end = rest.find(quote)?;
+        Some(decode_entities(&rest[..end]))
+    } else {
+        let end = rest
+            .find(|c: char| c.is_whitespace())
+            .unwrap_or(rest.len());
+        Some(decode_entities(&rest[..end]))
+    }
+}
+
+/// Decode HTML entities, including named forms and numeric `&#NN;` / `&#xNN;`.
+fn decode_entities(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    let mut rest = input;
+
+    while let Some(amp) = rest.find('&') {
+        out.push_str(&rest[..amp]);
+        let tail = &rest[amp..];
+        let Some(semi) = tail.find(';') else {
+            out.push('&');
+            rest = &tail[1..];
+            continue;
+        };
+        let entity = &tail[1..semi];
+        let decoded = match entity {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some(' '),
+            _ if entity.starts_with("#x") || entity.starts_with("#X") => {
+                u32::from_str_radix(&entity[2..], 16).ok().and_then(char::from_u32)
+            }
+            _ if entity.starts_with('#') => {
+                entity[1..].parse::<u32>().ok().and_then(char::from_u32)
             }
+            _ => None,
+        };
+
+        match decoded {
+            Some(ch) => out.push(ch),
+            None => out.push_str(&tail[..=semi]),
         }
+        rest = &tail[semi + 1..];
     }
 
-    result
+    out.push_str(rest);
+    out
 }
@@ -1,13 +1,106 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Duration;
+
 use futures::future::join_all;
+#[cfg(not(target_arch = "wasm32"))]
+use tokio::sync::Semaphore;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 const DEFAULT_BASE_URL: &str = "https://hacker-news.firebaseio.com/v0";
-const TOP_STORIES_PATH: &str = "/topstories.json";
 const ITEM_PATH: &str = "/item/";
+const ALGOLIA_BASE_URL: &str = "https://hn.algolia.com/api/v1";
+const DEFAULT_CACHE_TTL_SECS: u64 = 300;
+
+/// Per-request timeout applied to the pooled client on native targets.
+#[cfg(not(target_arch = "wasm32"))]
+const DEFAULT_TIMEOUT_SECS: u64 = 30;
+/// How many requests may be in flight at once across the `join_all` fan-out.
+#[cfg(not(target_arch = "wasm32"))]
+const DEFAULT_MAX_CONCURRENCY: usize = 16;
+/// Number of retries attempted on a transient failure before giving up.
+#[cfg(not(target_arch = "wasm32"))]
+const DEFAULT_MAX_RETRIES: u32 = 3;
+/// Base delay for the exponential backoff between retries, in milliseconds.
+#[cfg(not(target_arch = "wasm32"))]
+const RETRY_BASE_DELAY_MS: u64 = 200;
 
 pub type Result<T> = std::result::Result<T, HnError>;
 
+/// The distinct story feeds exposed by the Firebase API.
+///
+/// Each variant maps to one of the `*.json` index endpoints documented at
+/// <https://github.com/HackerNews/API>.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StoryFeed {
+    Top,
+    New,
+    Best,
+    Ask,
+    Show,
+    Job,
+}
+
+impl StoryFeed {
+    /// All feeds in display order, suitable for cycling in a UI.
+    pub const ALL: [StoryFeed; 6] = [
+        StoryFeed::Top,
+        StoryFeed::New,
+        StoryFeed::Best,
+        StoryFeed::Ask,
+        StoryFeed::Show,
+        StoryFeed::Job,
+    ];
+
+    /// The index endpoint path for this feed.
+    pub fn path(self) -> &'static str {
+        match self {
+            StoryFeed::Top => "/topstories.json",
+            StoryFeed::New => "/newstories.json",
+            StoryFeed::Best => "/beststories.json",
+            StoryFeed::Ask => "/askstories.json",
+            StoryFeed::Show => "/showstories.json",
+            StoryFeed::Job => "/jobstories.json",
+        }
+    }
+
+    /// A short human-readable name for headers and menus.
+    pub fn label(self) -> &'static str {
+        match self {
+            StoryFeed::Top => "Top",
+            StoryFeed::New => "New",
+            StoryFeed::Best => "Best",
+            StoryFeed::Ask => "Ask",
+            StoryFeed::Show => "Show",
+            StoryFeed::Job => "Job",
+        }
+    }
+
+    /// The next feed when cycling, wrapping back to the first.
+    pub fn next(self) -> StoryFeed {
+        let idx = Self::ALL.iter().position(|f| *f == self).unwrap_or(0);
+        Self::ALL[(idx + 1) % Self::ALL.len()]
+    }
+}
+
+impl Default for StoryFeed {
+    fn default() -> Self {
+        StoryFeed::Top
+    }
+}
+
+impl StoryFeed {
+    /// Parse a feed from its short name (`top`, `new`, `best`, …), as accepted
+    /// on the command line. Returns `None` for an unknown name.
+    pub fn from_name(name: &str) -> Option<StoryFeed> {
+        Self::ALL
+            .into_iter()
+            .find(|feed| feed.label().eq_ignore_ascii_case(name))
+    }
+}
+
 #[derive(Debug, Error)]
 pub enum HnError {
     #[error("http request failed: {0}")]
@@ -50,15 +143,168 @@ pub struct HackerNewsComment {
     pub time: Option<u64>,
 }
 
+/// Numeric and tag constraints for an Algolia search.
+///
+/// Each populated field contributes one clause to the `numericFilters` query
+/// parameter (e.g. `points>=100,created_at_i>1700000000`); `tags` is emitted
+/// verbatim as the `tags` parameter (e.g. `story`, `(story,show_hn)`).
+#[derive(Debug, Clone, Default)]
+pub struct StoryNumericFilters {
+    pub min_points: Option<i64>,
+    pub max_points: Option<i64>,
+    pub min_comments: Option<u64>,
+    /// Exclusive lower bound on creation time, as a Unix timestamp
+    /// (emitted as `created_at_i>{v}`).
+    pub created_after: Option<u64>,
+    /// Exclusive upper bound on creation time, as a Unix timestamp
+    /// (emitted as `created_at_i<{v}`).
+    pub created_before: Option<u64>,
+    /// Algolia `tags` filter, e.g. `story`, `comment`, `(story,show_hn)`.
+    pub tags: Option<String>,
+}
+
+impl StoryNumericFilters {
+    /// Render the populated bounds as an Algolia `numericFilters` value.
+    fn numeric_filters(&self) -> String {
+        let mut clauses = Vec::new();
+        if let Some(v) = self.min_points {
+            clauses.push(format!("points>={v}"));
+        }
+        if let Some(v) = self.max_points {
+            clauses.push(format!("points<={v}"));
+        }
+        if let Some(v) = self.min_comments {
+            clauses.push(format!("num_comments>={v}"));
+        }
+        if let Some(v) = self.created_after {
+            clauses.push(format!("created_at_i>{v}"));
+        }
+        if let Some(v) = self.created_before {
+            clauses.push(format!("created_at_i<{v}"));
+        }
+        clauses.join(",")
+    }
+}
+
+/// A single Algolia search hit, deserialised from its idiosyncratic field names
+/// (`objectID`, `num_comments`, `story_text`) before being lifted into a
+/// [`HackerNewsItem`].
+#[derive(Debug, Clone, Deserialize)]
+struct AlgoliaHit {
+    #[serde(rename = "objectID")]
+    object_id: String,
+    #[serde(default)]
+    title: Option<String>,
+    #[serde(default)]
+    author: String,
+    #[serde(default)]
+    points: Option<i64>,
+    #[serde(default)]
+    url: Option<String>,
+    #[serde(default)]
+    num_comments: Option<u64>,
+    #[serde(default)]
+    created_at_i: Option<u64>,
+    #[serde(default)]
+    story_text: Option<String>,
+}
+
+impl From<AlgoliaHit> for HackerNewsItem {
+    fn from(hit: AlgoliaHit) -> Self {
+        HackerNewsItem {
+            id: hit.object_id.parse().unwrap_or_default(),
+            title: hit.title.unwrap_or_default(),
+            by: hit.author,
+            score: hit.points.unwrap_or_default(),
+            url: hit.url,
+            time: hit.created_at_i,
+            text: hit.story_text,
+            kids: Vec::new(),
+            descendants: hit.num_comments,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct AlgoliaResponse {
+    #[serde(default)]
+    hits: Vec<AlgoliaHit>,
+}
+
+/// A comment together with the subtree of replies beneath it.
 #[derive(Debug, Clone)]
+pub struct CommentNode {
+    pub comment: HackerNewsComment,
+    pub replies: Vec<CommentNode>,
+}
+
+/// The boxed future built for each level of the comment-tree traversal. On
+/// native targets it is `Send`, so the traversal can run on a spawned task.
+#[cfg(not(target_arch = "wasm32"))]
+type LevelFuture<'a> =
+    std::pin::Pin<Box<dyn std::future::Future<Output = Vec<CommentNode>> + Send + 'a>>;
+#[cfg(target_arch = "wasm32")]
+type LevelFuture<'a> = std::pin::Pin<Box<dyn std::future::Future<Output = Vec<CommentNode>> + 'a>>;
+
+/// A cached `/item/` payload together with the time it was fetched.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedItem {
+    /// Unix timestamp (seconds) the entry was stored.
+    pub fetched_at: u64,
+    /// The raw item JSON, deserialised into whichever record the caller wants.
+    pub data: serde_json::Value,
+}
+
+/// A persistent store for fetched items and comments, keyed by HN id.
+pub trait Cache: Send + Sync {
+    fn get(&self, id: u64) -> Option<CachedItem>;
+    fn put(&self, id: u64, data: serde_json::Value, fetched_at: u64);
+}
+
+#[derive(Clone)]
 pub struct HackerNewsClient {
     base_url: String,
+    cache: Option<Arc<dyn Cache>>,
+    cache_ttl: u64,
+    /// Pooled, keep-alive'd HTTP client shared across the story and comment
+    /// fan-out so connections and TLS sessions are reused rather than rebuilt
+    /// per request.
+    #[cfg(not(target_arch = "wasm32"))]
+    http: Arc<reqwest::Client>,
+    #[cfg(not(target_arch = "wasm32"))]
+    timeout: Duration,
+    /// Caps the number of concurrent in-flight requests so fetching hundreds of
+    /// ids doesn't open a socket per id.
+    #[cfg(not(target_arch = "wasm32"))]
+    semaphore: Arc<Semaphore>,
+    #[cfg(not(target_arch = "wasm32"))]
+    max_retries: u32,
+}
+
+impl std::fmt::Debug for HackerNewsClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HackerNewsClient")
+            .field("base_url", &self.base_url)
+            .field("cached", &self.cache.is_some())
+            .field("cache_ttl", &self.cache_ttl)
+            .finish()
+    }
 }
 
 impl Default for HackerNewsClient {
     fn default() -> Self {
         Self {
             base_url: DEFAULT_BASE_URL.to_string(),
+            cache: None,
+            cache_ttl: DEFAULT_CACHE_TTL_SECS,
+            #[cfg(not(target_arch = "wasm32"))]
+            http: Arc::new(reqwest::Client::new()),
+            #[cfg(not(target_arch = "wasm32"))]
+            timeout: Duration::from_secs(DEFAULT_TIMEOUT_SECS),
+            #[cfg(not(target_arch = "wasm32"))]
+            semaphore: Arc::new(Semaphore::new(DEFAULT_MAX_CONCURRENCY)),
+            #[cfg(not(target_arch = "wasm32"))]
+            max_retries: DEFAULT_MAX_RETRIES,
         }
     }
 }
@@ -67,11 +313,66 @@ impl HackerNewsClient {
     pub fn new(base_url: impl Into<String>) -> Self {
         Self {
             base_url: base_url.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Attach a persistent cache with the given freshness window (seconds).
+    /// Entries no older than `ttl_seconds` are served without a network call.
+    pub fn with_cache(mut self, cache: Arc<dyn Cache>, ttl_seconds: u64) -> Self {
+        self.cache = Some(cache);
+        self.cache_ttl = ttl_seconds;
+        self
+    }
+
+    /// Set the per-request timeout applied to every HTTP call.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Bound how many requests may be in flight at once across the fan-out.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn with_max_concurrency(mut self, max: usize) -> Self {
+        self.semaphore = Arc::new(Semaphore::new(max.max(1)));
+        self
+    }
+
+    /// Set how many times a transient failure is retried before giving up.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn with_max_retries(mut self, retries: u32) -> Self {
+        self.max_retries = retries;
+        self
+    }
+
+    /// Read a still-fresh payload for `id` from the cache, if present.
+    fn cached_fresh(&self, id: u64) -> Option<serde_json::Value> {
+        let entry = self.cache.as_ref()?.get(id)?;
+        let age = now_unix().saturating_sub(entry.fetched_at);
+        (age <= self.cache_ttl).then_some(entry.data)
+    }
+
+    /// Store a freshly fetched record under `id`.
+    fn store<T: Serialize>(&self, id: u64, value: &T) {
+        if let Some(cache) = &self.cache {
+            if let Ok(data) = serde_json::to_value(value) {
+                cache.put(id, data, now_unix());
+            }
         }
     }
 
     pub async fn fetch_top_stories(&self, limit: usize) -> Result<Vec<HackerNewsItem>> {
-        let ids = self.fetch_top_story_ids().await?;
+        self.fetch_stories(StoryFeed::Top, limit).await
+    }
+
+    /// Fetch the first `limit` stories from the given feed.
+    pub async fn fetch_stories(
+        &self,
+        feed: StoryFeed,
+        limit: usize,
+    ) -> Result<Vec<HackerNewsItem>> {
+        let ids = self.fetch_story_ids(feed).await?;
         let requested = ids.into_iter().take(limit.max(1)).collect::<Vec<_>>();
 
         let futures = requested.into_iter().map(|id| self.fetch_item(id));
@@ -88,19 +389,28 @@ impl HackerNewsClient {
         Ok(items)
     }
 
-    async fn fetch_top_story_ids(&self) -> Result<Vec<u64>> {
-        let url = format!("{}{}", self.base_url, TOP_STORIES_PATH);
-        http_get_json::<Vec<u64>>(&url).await
+    async fn fetch_story_ids(&self, feed: StoryFeed) -> Result<Vec<u64>> {
+        let url = format!("{}{}", self.base_url, feed.path());
+        self.http_get_json::<Vec<u64>>(&url).await
     }
 
-    async fn fetch_item(&self, id: u64) -> Result<HackerNewsItem> {
+    /// Fetch a single item by id, consulting the cache first.
+    pub async fn fetch_item(&self, id: u64) -> Result<HackerNewsItem> {
+        if let Some(data) = self.cached_fresh(id) {
+            if let Ok(item) = serde_json::from_value::<HackerNewsItem>(data) {
+                return Ok(item);
+            }
+        }
+
         let url = format!(
             "{base}{item_path}{id}.json",
             base = self.base_url,
             item_path = ITEM_PATH,
             id = id
         );
-        http_get_json::<HackerNewsItem>(&url).await
+        let item = self.http_get_json::<HackerNewsItem>(&url).await?;
+        self.store(id, &item);
+        Ok(item)
     }
 
     pub async fn fetch_comments_for(
@@ -127,22 +437,230 @@ impl HackerNewsClient {
         Ok(comments)
     }
 
+    /// Fetch a comment and its descendants as a tree.
+    ///
+    /// Replies are gathered breadth-first: every node's `kids` are fetched
+    /// concurrently with [`join_all`] before descending a level. Traversal
+    /// stops once `max_depth` levels of replies have been collected, takes at
+    /// most `per_level_limit` replies per node, skips deleted items (those with
+    /// neither author nor text), and guards against id cycles.
+    pub async fn fetch_comment_thread(
+        &self,
+        root_id: u64,
+        max_depth: usize,
+        per_level_limit: usize,
+    ) -> Result<CommentNode> {
+        let comment = self.fetch_comment(root_id).await?;
+        let mut visited = HashSet::new();
+        visited.insert(root_id);
+        let mut roots = self
+            .build_level(vec![comment], max_depth, per_level_limit, &mut visited)
+            .await;
+        Ok(roots.pop().expect("root level always yields its single node"))
+    }
+
+    /// Build the reply subtrees for a whole level of comments at once.
+    ///
+    /// The next level's ids are collected across *every* comment in `parents`
+    /// and fetched together with a single [`join_all`], so sibling subtrees are
+    /// explored concurrently rather than one at a time, before recursing into
+    /// the level below. The returned nodes line up with `parents` in order.
+    fn build_level<'a>(
+        &'a self,
+        parents: Vec<HackerNewsComment>,
+        max_depth: usize,
+        per_level_limit: usize,
+        visited: &'a mut HashSet<u64>,
+    ) -> LevelFuture<'a> {
+        Box::pin(async move {
+            if max_depth == 0 {
+                return parents
+                    .into_iter()
+                    .map(|comment| CommentNode {
+                        comment,
+                        replies: Vec::new(),
+                    })
+                    .collect();
+            }
+
+            // The child ids each parent wants, plus the flattened set to fetch.
+            let mut child_ids: Vec<Vec<u64>> = Vec::with_capacity(parents.len());
+            let mut flat: Vec<u64> = Vec::new();
+            for parent in &parents {
+                let ids = parent
+                    .kids
+                    .iter()
+                    .copied()
+                    .filter(|id| visited.insert(*id))
+                    .take(per_level_limit)
+                    .collect::<Vec<_>>();
+                flat.extend(ids.iter().copied());
+                child_ids.push(ids);
+            }
+
+            if flat.is_empty() {
+                return parents
+                    .into_iter()
+                    .map(|comment| CommentNode {
+                        comment,
+                        replies: Vec::new(),
+                    })
+                    .collect();
+            }
+
+            // One fan-out for the entire level.
+            let fetched = join_all(flat.into_iter().map(|id| self.fetch_comment(id))).await;
+            let mut by_id: HashMap<u64, HackerNewsComment> = HashMap::new();
+            for res in fetched {
+                match res {
+                    Ok(comment) if !is_deleted(&comment) => {
+                        by_id.insert(comment.id, comment);
+                    }
+                    Ok(_) => {}
+                    Err(err) => tracing::warn!(?err, "skipping reply fetch failure"),
+                }
+            }
+
+            // The surviving children become the next level, in parent order.
+            let next_level = child_ids
+                .iter()
+                .flatten()
+                .filter_map(|id| by_id.get(id).cloned())
+                .collect::<Vec<_>>();
+            let built = self
+                .build_level(next_level, max_depth - 1, per_level_limit, visited)
+                .await;
+            let mut built_by_id: HashMap<u64, CommentNode> =
+                built.into_iter().map(|node| (node.comment.id, node)).collect();
+
+            // Reattach each built subtree to its parent.
+            parents
+                .into_iter()
+                .zip(child_ids)
+                .map(|(comment, ids)| {
+                    let replies = ids
+                        .iter()
+                        .filter_map(|id| built_by_id.remove(id))
+                        .collect();
+                    CommentNode { comment, replies }
+                })
+                .collect()
+        })
+    }
+
+    /// Search HN via Algolia, ranked by relevance.
+    pub async fn search(
+        &self,
+        query: &str,
+        filters: &StoryNumericFilters,
+    ) -> Result<Vec<HackerNewsItem>> {
+        self.run_search("/search", query, filters).await
+    }
+
+    /// Search HN via Algolia, ranked by recency.
+    pub async fn search_by_date(
+        &self,
+        query: &str,
+        filters: &StoryNumericFilters,
+    ) -> Result<Vec<HackerNewsItem>> {
+        self.run_search("/search_by_date", query, filters).await
+    }
+
+    async fn run_search(
+        &self,
+        path: &str,
+        query: &str,
+        filters: &StoryNumericFilters,
+    ) -> Result<Vec<HackerNewsItem>> {
+        let mut url = format!(
+            "{base}{path}?query={query}",
+            base = ALGOLIA_BASE_URL,
+            query = encode_query(query),
+        );
+
+        let numeric = filters.numeric_filters();
+        if !numeric.is_empty() {
+            url.push_str(&format!("&numericFilters={}", encode_query(&numeric)));
+        }
+        if let Some(tags) = &filters.tags {
+            url.push_str(&format!("&tags={}", encode_query(tags)));
+        }
+
+        let response = self.http_get_json::<AlgoliaResponse>(&url).await?;
+        Ok(response.hits.into_iter().map(HackerNewsItem::from).collect())
+    }
+
     async fn fetch_comment(&self, id: u64) -> Result<HackerNewsComment> {
+        if let Some(data) = self.cached_fresh(id) {
+            if let Ok(comment) = serde_json::from_value::<HackerNewsComment>(data) {
+                return Ok(comment);
+            }
+        }
+
         let url = format!(
             "{base}{item_path}{id}.json",
             base = self.base_url,
             item_path = ITEM_PATH,
             id = id
         );
-        http_get_json::<HackerNewsComment>(&url).await
+        let comment = self.http_get_json::<HackerNewsComment>(&url).await?;
+        self.store(id, &comment);
+        Ok(comment)
+    }
+
+    /// Issue a GET through the pooled client and deserialise the JSON body.
+    ///
+    /// A concurrency permit is held for the duration of the request so the
+    /// fan-out never exceeds the configured in-flight limit, and transient
+    /// failures (timeouts, connection resets, `5xx`, `429`) are retried with
+    /// exponential backoff up to `max_retries` times before surfacing.
+    #[cfg(not(target_arch = "wasm32"))]
+    async fn http_get_json<T>(&self, url: &str) -> Result<T>
+    where
+        T: for<'de> Deserialize<'de>,
+    {
+        let _permit = self.semaphore.acquire().await;
+
+        let mut attempt = 0u32;
+        loop {
+            let outcome = self.http.get(url).timeout(self.timeout).send().await;
+
+            let transient = match &outcome {
+                Ok(response) => {
+                    let status = response.status();
+                    status.is_server_error()
+                        || status == reqwest::StatusCode::TOO_MANY_REQUESTS
+                }
+                Err(err) => err.is_timeout() || err.is_connect() || err.is_request(),
+            };
+
+            if !transient {
+                let response = outcome.map_err(|err| HnError::Http(err.to_string()))?;
+                return response
+                    .json::<T>()
+                    .await
+                    .map_err(|err| HnError::Deserialize(err.to_string()));
+            }
+
+            if attempt >= self.max_retries {
+                return Err(match outcome {
+                    Ok(response) => {
+                        HnError::Http(format!("server returned {}", response.status()))
+                    }
+                    Err(err) => HnError::Http(err.to_string()),
+                });
+            }
+
+            let backoff = RETRY_BASE_DELAY_MS.saturating_mul(1u64 << attempt.min(16));
+            tokio::time::sleep(Duration::from_millis(backoff)).await;
+            attempt += 1;
+        }
     }
-}
 
-async fn http_get_json<T>(url: &str) -> Result<T>
-where
-    T: for<'de> Deserialize<'de>,
-{
     #[cfg(target_arch = "wasm32")]
+    async fn http_get_json<T>(&self, url: &str) -> Result<T>
+    where
+        T: for<'de> Deserialize<'de>,
     {
         use gloo_net::http::Request;
         let response = Request::get(url)
@@ -154,15 +672,152 @@ where
             .await
             .map_err(|err| HnError::Deserialize(err.to_string()))
     }
+}
 
+/// The current Unix time in whole seconds.
+fn now_unix() -> u64 {
     #[cfg(not(target_arch = "wasm32"))]
     {
-        let response = reqwest::get(url)
-            .await
-            .map_err(|err| HnError::Http(err.to_string()))?;
-        response
-            .json::<T>()
-            .await
-            .map_err(|err| HnError::Deserialize(err.to_string()))
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0)
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    {
+        (js_sys::Date::now() / 1000.0) as u64
+    }
+}
+
+/// A filesystem-backed [`Cache`] that stores one JSON file per id under a
+/// cache directory.
+#[cfg(not(target_arch = "wasm32"))]
+pub struct FileCache {
+    dir: std::path::PathBuf,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl FileCache {
+    /// A cache rooted at an `hn` directory under the user's default cache
+    /// directory (`$XDG_CACHE_HOME` or `~/.cache`, falling back to the system
+    /// temp dir).
+    pub fn new() -> Self {
+        Self::with_dir(default_cache_dir())
     }
+
+    /// A cache rooted at an explicit directory.
+    pub fn with_dir(dir: impl Into<std::path::PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    fn path(&self, id: u64) -> std::path::PathBuf {
+        self.dir.join(format!("{id}.json"))
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Default for FileCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Cache for FileCache {
+    fn get(&self, id: u64) -> Option<CachedItem> {
+        let contents = std::fs::read_to_string(self.path(id)).ok()?;
+        serde_json::from_str(&contents).ok()
+    }
+
+    fn put(&self, id: u64, data: serde_json::Value, fetched_at: u64) {
+        if std::fs::create_dir_all(&self.dir).is_err() {
+            return;
+        }
+        let entry = CachedItem { fetched_at, data };
+        if let Ok(serialised) = serde_json::to_string(&entry) {
+            let _ = std::fs::write(self.path(id), serialised);
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn default_cache_dir() -> std::path::PathBuf {
+    use std::path::PathBuf;
+    if let Ok(dir) = std::env::var("XDG_CACHE_HOME") {
+        return PathBuf::from(dir).join("hn");
+    }
+    if let Ok(home) = std::env::var("HOME") {
+        return PathBuf::from(home).join(".cache").join("hn");
+    }
+    std::env::temp_dir().join("hn")
+}
+
+/// A `localStorage`-backed [`Cache`] for the `wasm32` target.
+#[cfg(target_arch = "wasm32")]
+pub struct LocalStorageCache {
+    prefix: String,
+}
+
+#[cfg(target_arch = "wasm32")]
+impl LocalStorageCache {
+    pub fn new() -> Self {
+        Self {
+            prefix: "hn_item_".to_string(),
+        }
+    }
+
+    fn storage(&self) -> Option<web_sys::Storage> {
+        web_sys::window()?.local_storage().ok().flatten()
+    }
+
+    fn key(&self, id: u64) -> String {
+        format!("{}{id}", self.prefix)
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+impl Default for LocalStorageCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+impl Cache for LocalStorageCache {
+    fn get(&self, id: u64) -> Option<CachedItem> {
+        let raw = self.storage()?.get_item(&self.key(id)).ok()??;
+        serde_json::from_str(&raw).ok()
+    }
+
+    fn put(&self, id: u64, data: serde_json::Value, fetched_at: u64) {
+        if let Some(storage) = self.storage() {
+            let entry = CachedItem { fetched_at, data };
+            if let Ok(serialised) = serde_json::to_string(&entry) {
+                let _ = storage.set_item(&self.key(id), &serialised);
+            }
+        }
+    }
+}
+
+/// Percent-encode a query-parameter value, escaping everything outside the
+/// unreserved set so clauses like `points>=100,created_at_i>1700000000` survive
+/// transit intact.
+fn encode_query(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                out.push(byte as char);
+            }
+            _ => out.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    out
+}
+
+/// A comment is considered deleted when its id resolved but it carries neither
+/// an author nor any text.
+fn is_deleted(comment: &HackerNewsComment) -> bool {
+    comment.by.is_empty() && comment.text.is_empty()
 }
@@ -1,6 +1,10 @@
-use hn_core::HackerNewsClient;
+use std::sync::Arc;
+
+use hn_core::{HackerNewsClient, LocalStorageCache};
 use wasm_bindgen::prelude::*;
 
+const CACHE_TTL_SECS: u64 = 600;
+
 #[wasm_bindgen]
 pub fn init_panic_hook() {
     console_error_panic_hook::set_once();
@@ -8,7 +12,8 @@ pub fn init_panic_hook() {
 
 #[wasm_bindgen]
 pub async fn fetch_top_posts(limit: u32) -> Result<JsValue, JsValue> {
-    let client = HackerNewsClient::default();
+    let client = HackerNewsClient::default()
+        .with_cache(Arc::new(LocalStorageCache::new()), CACHE_TTL_SECS);
     let posts = client
         .fetch_top_stories(limit as usize)
         .await